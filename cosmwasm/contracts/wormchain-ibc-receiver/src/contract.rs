@@ -1,8 +1,22 @@
-use anyhow::Context;
-use cosmwasm_std::{entry_point, Empty, StdError};
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
-use cw2::{set_contract_version, get_contract_version};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+
+use anyhow::{ensure, Context};
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Empty, Env, Event, IbcBasicResponse, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketReceiveMsg, IbcReceiveResponse,
+    MessageInfo, MigrateInfo, Order, Response, StdError,
+};
+use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
+use wormhole::contract::query_parse_and_verify_vaa;
+use wormhole::msg::InstantiateMsg;
+use wormhole_sdk::Chain;
+
+use crate::bail;
+use crate::error::ContractError;
+use crate::msg::{ChainConnectionAction, ChainConnectionGovernancePacket, ChainConnectionResponse, ExecuteMsg, QueryMsg, WormholeIbcPacketMsg};
+use crate::state::{CHANNEL_CHAIN, CHANNEL_CONNECTION_ID, CHAIN_CONNECTIONS};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:wormchain-ibc-receiver";
@@ -11,21 +25,36 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    _msg: Empty,
+    msg: InstantiateMsg,
 ) -> Result<Response, anyhow::Error> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
         .context("failed to set contract version")?;
 
-    Ok(Response::new()
-        .add_attribute("action", "instantiate")
-        .add_attribute("owner", info.sender)
-        .add_attribute("version", CONTRACT_VERSION))
+    // this contract verifies governance VAAs itself, so it carries its own
+    // copy of the core bridge's guardian set state
+    wormhole::contract::instantiate(deps, env, info, msg)
+        .context("wormhole core instantiation failed")
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, anyhow::Error> {
+pub fn migrate(
+    deps: DepsMut,
+    env: Env,
+    _msg: Empty,
+    migrate_info: MigrateInfo,
+) -> Result<Response, anyhow::Error> {
+    // reject migrations not triggered by the contract's on-chain wasm admin
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(env.contract.address.clone())
+        .context("failed to query contract info")?;
+    ensure!(
+        contract_info.admin.as_deref() == Some(migrate_info.sender.as_str()),
+        "migration must be initiated by the configured admin"
+    );
+
     let ver = get_contract_version(deps.storage)?;
     // ensure we are migrating from an allowed contract
     if ver.contract != CONTRACT_NAME {
@@ -41,51 +70,507 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, anyhow
         return Err(StdError::generic_err("Cannot upgrade from a newer or equal version").into());
     }
 
+    // no state change introduced so far requires gating on the exact
+    // version we're upgrading from; add a check against
+    // `migrate_info.previous_version` here if a future migration does
+
     // set the new version
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::default())
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> anyhow::Result<Response> {
+    match msg {
+        ExecuteMsg::SubmitUpdateChainConnection { vaas } => {
+            handle_submit_update_chain_connection(deps, env, info, vaas)
+        }
+    }
+}
+
+fn handle_submit_update_chain_connection(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vaas: Vec<Binary>,
+) -> anyhow::Result<Response> {
+    let mut response = Response::new()
+        .add_attribute("action", "submit_update_chain_connection")
+        .add_attribute("owner", info.sender);
+
+    for vaa in vaas {
+        let vaa = query_parse_and_verify_vaa(deps.as_ref(), vaa.as_slice(), env.block.time.seconds())
+            .context("failed to parse and verify vaa")?;
+
+        // ensure it's a governance VAA (from solana and the special governance emitter)
+        if !(Chain::from(vaa.emitter_chain) == Chain::Solana
+            && vaa.emitter_address == wormhole_sdk::GOVERNANCE_EMITTER.0)
+        {
+            bail!(ContractError::InvalidVAAType);
+        }
+
+        let govpacket: ChainConnectionGovernancePacket = serde_wormhole::from_slice(&vaa.payload)
+            .context("failed to parse governance packet")?;
+
+        ensure!(
+            govpacket.chain == Chain::Wormchain,
+            "this governance VAA is for another chain"
+        );
+
+        let ChainConnectionAction::UpdateChainConnection {
+            chain_id,
+            connection_id,
+        } = govpacket.action;
+
+        CHAIN_CONNECTIONS
+            .save(deps.storage, chain_id, &connection_id)
+            .context("failed to save chain connection")?;
+
+        response = response.add_event(
+            Event::new("UpdateChainConnection")
+                .add_attribute("chain", chain_id.to_string())
+                .add_attribute("connection_id", connection_id.to_string()),
+        );
+    }
+
+    Ok(response)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> anyhow::Result<Binary> {
+    match msg {
+        QueryMsg::ChainConnection(chain) => to_binary(&query_chain_connection(deps, chain)?).map_err(Into::into),
+    }
+}
+
+fn query_chain_connection(deps: Deps, chain: u16) -> anyhow::Result<ChainConnectionResponse> {
+    let connection_id = CHAIN_CONNECTIONS
+        .load(deps.storage, chain)
+        .context("no connection registered for this chain")?;
+    Ok(ChainConnectionResponse { connection_id })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, StdError> {
+    // accept the counterparty's proposed version as-is
+    Ok(None)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> anyhow::Result<IbcBasicResponse> {
+    let channel = msg.channel();
+    let channel_id = channel.endpoint.channel_id.clone();
+
+    let chain = resolve_chain_for_connection(deps.as_ref(), &channel.connection_id)
+        .context("no chain is registered for this channel's connection")?;
+
+    CHANNEL_CHAIN
+        .save(deps.storage, channel_id.clone(), &chain)
+        .context("failed to save channel registration")?;
+    CHANNEL_CONNECTION_ID
+        .save(
+            deps.storage,
+            channel_id.clone(),
+            &Binary::from(channel.connection_id.as_bytes().to_vec()),
+        )
+        .context("failed to save channel connection_id")?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_connect")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("chain", chain.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, StdError> {
+    match handle_ibc_packet_receive(deps, msg) {
+        Ok(response) => Ok(response),
+        Err(err) => Ok(IbcReceiveResponse::new()
+            .set_ack(to_binary(&format!("error: {err}"))?)
+            .add_attribute("action", "ibc_packet_receive")
+            .add_attribute("error", err.to_string())),
+    }
+}
+
+fn handle_ibc_packet_receive(
+    deps: DepsMut,
+    msg: IbcPacketReceiveMsg,
+) -> anyhow::Result<IbcReceiveResponse> {
+    let channel_id = msg.packet.dest.channel_id.clone();
+
+    let chain = CHANNEL_CHAIN
+        .load(deps.storage, channel_id.clone())
+        .map_err(|_| ContractError::UnrecognizedChannel)?;
+
+    // re-check the channel's connection_id (captured once, at connect time)
+    // against the currently registered one, in case governance revoked or
+    // rotated the connection for this chain after the channel was connected
+    let channel_connection_id = CHANNEL_CONNECTION_ID
+        .load(deps.storage, channel_id.clone())
+        .context("no connection_id recorded for this channel")?;
+    let registered_connection_id = CHAIN_CONNECTIONS
+        .load(deps.storage, chain)
+        .context("no connection registered for this chain")?;
+
+    if channel_connection_id != registered_connection_id {
+        bail!(ContractError::InvalidConnection);
+    }
+
+    let packet: WormholeIbcPacketMsg = cosmwasm_std::from_binary(&msg.packet.data)
+        .context("failed to parse wormhole ibc packet")?;
+    let WormholeIbcPacketMsg::Publish { msg: published, .. } = packet;
+
+    Ok(IbcReceiveResponse::new()
+        .set_ack(to_binary(&"ok")?)
+        .add_attributes(published.attributes)
+        .add_events(published.events)
+        .add_attribute("action", "ibc_packet_receive")
+        .add_attribute("chain", chain.to_string()))
+}
+
+/// Find the chain registered (via governance) for `connection_id`, if any.
+fn resolve_chain_for_connection(deps: Deps, connection_id: &str) -> anyhow::Result<u16> {
+    CHAIN_CONNECTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .find_map(|entry| {
+            let (chain, registered) = entry.ok()?;
+            let registered = String::from_utf8(registered.to_vec()).ok()?;
+            (registered == connection_id).then_some(chain)
+        })
+        .context("no chain registered for this connection_id")
+}
+
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::{
-        mock_dependencies, mock_info, mock_env
-    }, Empty};
-    use cw2::get_contract_version;
-    
-    use super::{instantiate, CONTRACT_NAME, CONTRACT_VERSION};
-    
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, mock_ibc_channel, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{Addr, Binary, Coin, ContractInfoResponse, ContractResult, Empty,
+        IbcChannelConnectMsg, IbcEndpoint, IbcOrder, IbcPacket, IbcPacketReceiveMsg, IbcTimeout,
+        MigrateInfo, OwnedDeps, Response, SystemResult, Timestamp, WasmQuery};
+    use cw2::{get_contract_version, set_contract_version};
+    use wormhole::msg::InstantiateMsg as CoreInstantiateMsg;
+    use wormhole::state::{GuardianAddress, GuardianSetInfo};
+    use wormhole_ibc::test_utils::MockGuardianSet;
+    use wormhole_sdk::Chain;
+
+    use super::{
+        execute, ibc_channel_connect, ibc_packet_receive, instantiate, migrate, query,
+        CONTRACT_NAME, CONTRACT_VERSION,
+    };
+    use crate::error::ContractError;
+    use crate::msg::{
+        ChainConnectionAction, ChainConnectionGovernancePacket, ChainConnectionResponse,
+        ExecuteMsg, QueryMsg, WormholeIbcPacketMsg,
+    };
+
+    const SENDER: &str = "creator";
+
+    fn sign_update_chain_connection(
+        guardians: &MockGuardianSet,
+        chain_id: u16,
+        connection_id: &[u8],
+    ) -> Binary {
+        let govpacket = ChainConnectionGovernancePacket {
+            chain: Chain::Wormchain,
+            action: ChainConnectionAction::UpdateChainConnection {
+                chain_id,
+                connection_id: Binary::from(connection_id.to_vec()),
+            },
+        };
+        let payload = serde_wormhole::to_vec(&govpacket).unwrap();
+        guardians.sign_vaa(Chain::Solana, wormhole_sdk::GOVERNANCE_EMITTER, 0, payload)
+    }
+
+    fn setup() -> (OwnedDeps<MockStorage, MockApi, MockQuerier>, MockGuardianSet) {
+        let guardians = MockGuardianSet::new();
+        let mut deps = mock_dependencies();
+
+        let msg = CoreInstantiateMsg {
+            gov_chain: Chain::Solana.into(),
+            gov_address: Binary::from(wormhole_sdk::GOVERNANCE_EMITTER.0.to_vec()),
+            guardian_set_expirity: 86400,
+            initial_guardian_set: GuardianSetInfo {
+                addresses: guardians
+                    .addresses()
+                    .into_iter()
+                    .map(|bytes| GuardianAddress {
+                        bytes: Binary::from(bytes.to_vec()),
+                    })
+                    .collect(),
+                expiration_time: 0,
+            },
+            chain_id: Chain::Wormchain.into(),
+            fee: Coin::new(0, "uworm"),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(SENDER, &[]), msg).unwrap();
+        (deps, guardians)
+    }
+
     #[test]
     fn instantiate_works() {
         let mut deps = mock_dependencies();
-    
-        const SENDER: &str = "creator";
+
         let info = mock_info(SENDER, &[]);
-        let res = instantiate(deps.as_mut(), mock_env(), info, Empty {}).unwrap();
-    
-        // the response should have 0 messages and 3 attributes
-        assert_eq!(0, res.messages.len());
-        assert_eq!(3, res.attributes.len());
-
-        // validate the attributes and their values
-        res.attributes.iter().for_each(|a| {
-            let value = if a.key == "action" {
-                "instantiate"
-            } else if a.key == "owner" {
-                SENDER
-            } else if a.key == "version" {
-                CONTRACT_VERSION
-            } else {
-                panic!("invalid attribute key");
-            };
-
-            assert_eq!(a.value, value);
-        });
-    
+        let msg = CoreInstantiateMsg {
+            gov_chain: Chain::Solana.into(),
+            gov_address: Binary::from(wormhole_sdk::GOVERNANCE_EMITTER.0.to_vec()),
+            guardian_set_expirity: 86400,
+            initial_guardian_set: GuardianSetInfo {
+                addresses: vec![],
+                expiration_time: 0,
+            },
+            chain_id: Chain::Wormchain.into(),
+            fee: Coin::new(0, "uworm"),
+        };
+        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
         // check that contract version & name have been set
         let contract_version = get_contract_version(deps.as_ref().storage).unwrap();
         assert_eq!(CONTRACT_NAME, contract_version.contract);
         assert_eq!(CONTRACT_VERSION, contract_version.version);
+        // the response is the core bridge's own instantiate response, which
+        // carries no attributes
+        assert!(res.attributes.is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn register_connection_happy_path_and_query() {
+        let (mut deps, guardians) = setup();
+        let vaa = sign_update_chain_connection(&guardians, Chain::Ethereum.into(), b"connection-0");
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateChainConnection { vaas: vec![vaa] },
+        )
+        .unwrap();
+
+        let res: ChainConnectionResponse = cosmwasm_std::from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ChainConnection(Chain::Ethereum.into()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.connection_id, Binary::from(b"connection-0".to_vec()));
+    }
+
+    fn mock_connect_msg(channel_id: &str, connection_id: &str) -> IbcChannelConnectMsg {
+        let mut channel = mock_ibc_channel(channel_id, IbcOrder::Unordered, "wormhole-ibc-v1");
+        channel.connection_id = connection_id.to_string();
+        IbcChannelConnectMsg::new_confirm(channel)
+    }
+
+    #[test]
+    fn ibc_channel_connect_rejects_unregistered_connection() {
+        let (mut deps, _guardians) = setup();
+        let err = ibc_channel_connect(
+            deps.as_mut(),
+            mock_env(),
+            mock_connect_msg("channel-0", "connection-0"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("no chain is registered"));
+    }
+
+    #[test]
+    fn ibc_channel_connect_accepts_registered_connection() {
+        let (mut deps, guardians) = setup();
+        let vaa = sign_update_chain_connection(&guardians, Chain::Ethereum.into(), b"connection-0");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateChainConnection { vaas: vec![vaa] },
+        )
+        .unwrap();
+
+        let res = ibc_channel_connect(
+            deps.as_mut(),
+            mock_env(),
+            mock_connect_msg("channel-0", "connection-0"),
+        )
+        .unwrap();
+        let expected_chain: u16 = Chain::Ethereum.into();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "chain" && a.value == expected_chain.to_string()));
+    }
+
+    fn mock_receive_msg(channel_id: &str, data: Binary) -> IbcPacketReceiveMsg {
+        let packet = IbcPacket::new(
+            data,
+            IbcEndpoint {
+                port_id: "wasm.counterparty".to_string(),
+                channel_id: "counterparty-channel".to_string(),
+            },
+            IbcEndpoint {
+                port_id: "wasm.receiver".to_string(),
+                channel_id: channel_id.to_string(),
+            },
+            0,
+            IbcTimeout::with_timestamp(Timestamp::from_seconds(0)),
+        );
+        IbcPacketReceiveMsg::new(packet, cosmwasm_std::Addr::unchecked("relayer"))
+    }
+
+    fn publish_packet_data() -> Binary {
+        cosmwasm_std::to_binary(&WormholeIbcPacketMsg::Publish {
+            id: 1,
+            msg: Response::new().add_attribute("sequence", "1"),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn publish_packet_round_trips_with_sender_side_type() {
+        // the two `WormholeIbcPacketMsg::Publish` copies must stay
+        // byte-compatible: `wormhole-ibc` is what actually serializes the
+        // packet data this contract deserializes in `handle_ibc_packet_receive`.
+        let sent = wormhole_ibc::msg::WormholeIbcPacketMsg::Publish {
+            id: 7,
+            msg: Response::new().add_attribute("sequence", "7"),
+        };
+        let data = cosmwasm_std::to_binary(&sent).unwrap();
+
+        let received: WormholeIbcPacketMsg = cosmwasm_std::from_binary(&data).unwrap();
+        let WormholeIbcPacketMsg::Publish { id, msg } = received;
+        assert_eq!(id, 7);
+        assert_eq!(msg.attributes, vec![cosmwasm_std::Attribute::new("sequence", "7")]);
+    }
+
+    #[test]
+    fn ibc_packet_receive_accepts_matching_connection() {
+        let (mut deps, guardians) = setup();
+        let vaa = sign_update_chain_connection(&guardians, Chain::Ethereum.into(), b"connection-0");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateChainConnection { vaas: vec![vaa] },
+        )
+        .unwrap();
+        ibc_channel_connect(
+            deps.as_mut(),
+            mock_env(),
+            mock_connect_msg("channel-0", "connection-0"),
+        )
+        .unwrap();
+
+        let res = ibc_packet_receive(
+            deps.as_mut(),
+            mock_env(),
+            mock_receive_msg("channel-0", publish_packet_data()),
+        )
+        .unwrap();
+        let ack: String = cosmwasm_std::from_binary(&res.acknowledgement).unwrap();
+        assert_eq!(ack, "ok");
+    }
+
+    #[test]
+    fn ibc_packet_receive_rejects_rotated_connection() {
+        let (mut deps, guardians) = setup();
+        let vaa = sign_update_chain_connection(&guardians, Chain::Ethereum.into(), b"connection-0");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateChainConnection { vaas: vec![vaa] },
+        )
+        .unwrap();
+        ibc_channel_connect(
+            deps.as_mut(),
+            mock_env(),
+            mock_connect_msg("channel-0", "connection-0"),
+        )
+        .unwrap();
+
+        // governance rotates the connection registered for this chain after
+        // the channel was already connected
+        let rotate_vaa =
+            sign_update_chain_connection(&guardians, Chain::Ethereum.into(), b"connection-1");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateChainConnection { vaas: vec![rotate_vaa] },
+        )
+        .unwrap();
+
+        let res = ibc_packet_receive(
+            deps.as_mut(),
+            mock_env(),
+            mock_receive_msg("channel-0", publish_packet_data()),
+        )
+        .unwrap();
+        let ack: String = cosmwasm_std::from_binary(&res.acknowledgement).unwrap();
+        assert!(ack.starts_with("error: "));
+        assert!(ack.contains(&ContractError::InvalidConnection.to_string()));
+    }
+
+    const ADMIN: &str = "admin";
+
+    fn mock_admin_querier(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::ContractInfo { .. } => {
+                let mut info = ContractInfoResponse::new(1, "creator");
+                info.admin = Some(ADMIN.to_string());
+                SystemResult::Ok(ContractResult::Ok(cosmwasm_std::to_binary(&info).unwrap()))
+            }
+            _ => panic!("unexpected query in migrate admin test"),
+        });
+    }
+
+    #[test]
+    fn migrate_rejects_non_admin_sender() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+        mock_admin_querier(&mut deps);
+
+        let migrate_info = MigrateInfo {
+            sender: Addr::unchecked("not-the-admin"),
+            previous_version: Some(CONTRACT_VERSION.to_string()),
+        };
+        let err = migrate(deps.as_mut(), mock_env(), Empty {}, migrate_info).unwrap_err();
+        assert!(err.to_string().contains("configured admin"));
+    }
+
+    #[test]
+    fn migrate_allows_configured_admin() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+        mock_admin_querier(&mut deps);
+
+        let migrate_info = MigrateInfo {
+            sender: Addr::unchecked(ADMIN),
+            previous_version: Some("0.0.1".to_string()),
+        };
+        migrate(deps.as_mut(), mock_env(), Empty {}, migrate_info).unwrap();
+
+        let version = get_contract_version(deps.as_ref().storage).unwrap();
+        assert_eq!(version.version, CONTRACT_VERSION);
+    }
+}
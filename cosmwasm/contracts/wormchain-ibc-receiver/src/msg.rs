@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Response, Binary};
+use wormhole_sdk::Chain;
 
 #[cw_serde]
 pub enum ExecuteMsg {
@@ -12,10 +13,17 @@ pub enum ExecuteMsg {
     }
 }
 
-/// This is the message we send over the IBC channel
+/// This is the message we send over the IBC channel. Must stay byte-compatible
+/// with `wormhole_ibc::msg::WormholeIbcPacketMsg`, the type the other side of
+/// this channel actually serializes packets with.
 #[cw_serde]
 pub enum WormholeIbcPacketMsg {
-    Publish { msg: Response }
+    Publish {
+        /// The sender's own per-channel sequence number; unused here, kept
+        /// only so this type deserializes the sender's wire format.
+        id: u64,
+        msg: Response,
+    },
 }
 
 /// Contract queries
@@ -29,4 +37,19 @@ pub enum QueryMsg {
 #[cw_serde]
 pub struct ChainConnectionResponse {
     pub connection_id: Binary,
+}
+
+/// The governance payload carried by a `SubmitUpdateChainConnection` VAA.
+#[cw_serde]
+pub struct ChainConnectionGovernancePacket {
+    pub chain: Chain,
+    pub action: ChainConnectionAction,
+}
+
+#[cw_serde]
+pub enum ChainConnectionAction {
+    /// Register (or override a previous registration of) the IBC
+    /// `connection_id` that packets claiming to originate from `chain` must
+    /// arrive over.
+    UpdateChainConnection { chain_id: u16, connection_id: Binary },
 }
\ No newline at end of file
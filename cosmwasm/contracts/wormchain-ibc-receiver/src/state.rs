@@ -0,0 +1,16 @@
+use cw_storage_plus::Map;
+use cosmwasm_std::Binary;
+
+/// The IBC `connection_id` each chain is allowed to submit packets over,
+/// registered via governance VAA.
+pub const CHAIN_CONNECTIONS: Map<u16, Binary> = Map::new("chain_connections");
+
+/// The chain a connected channel was opened for, resolved once at
+/// `ibc_channel_connect` time from the channel's `connection_id`.
+pub const CHANNEL_CHAIN: Map<String, u16> = Map::new("channel_chain");
+
+/// The `connection_id` a channel was actually connected over, captured once
+/// at `ibc_channel_connect` time (a channel's connection never changes for
+/// its lifetime) so `ibc_packet_receive` can detect a connection that was
+/// later revoked or rotated for this chain without re-querying IBC state.
+pub const CHANNEL_CONNECTION_ID: Map<String, Binary> = Map::new("channel_connection_id");
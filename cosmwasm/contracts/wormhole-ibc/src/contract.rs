@@ -3,8 +3,9 @@ use cosmwasm_std::entry_point;
 
 use anyhow::{ensure, Context};
 use cosmwasm_std::{
-    to_binary, DepsMut, Env, IbcChannel, IbcMsg, IbcQuery, ListChannelsResponse, MessageInfo,
-    Response, StdError, StdResult, Binary, Event,
+    to_binary, DepsMut, Env, IbcBasicResponse, IbcChannel, IbcMsg, IbcPacketAckMsg,
+    IbcPacketTimeoutMsg, IbcQuery, ListChannelsResponse, MessageInfo, MigrateInfo, Response,
+    StdError, StdResult, Binary, Event,
 };
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
@@ -17,7 +18,10 @@ use crate::bail;
 use crate::error::ContractError;
 use crate::ibc::PACKET_LIFETIME;
 use crate::msg::{ExecuteMsg, WormholeIbcPacketMsg};
-use crate::state::WORMCHAIN_IBC_RECEIVER_ADDR;
+use crate::state::{
+    OutgoingPacket, PacketStatus, NEXT_PACKET_SEQUENCE, OUTGOING_PACKETS, PROCESSED_GOVERNANCE_VAAS,
+    WORMCHAIN_IBC_RECEIVER_ADDR,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:wormhole-ibc";
@@ -40,7 +44,22 @@ pub fn instantiate(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, anyhow::Error> {
+pub fn migrate(
+    deps: DepsMut,
+    env: Env,
+    msg: MigrateMsg,
+    migrate_info: MigrateInfo,
+) -> Result<Response, anyhow::Error> {
+    // only the chain-configured admin may trigger a migration
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(env.contract.address.clone())
+        .context("failed to query contract info")?;
+    ensure!(
+        contract_info.admin.as_deref() == Some(migrate_info.sender.as_str()),
+        "migration must be initiated by the configured admin"
+    );
+
     let ver = get_contract_version(deps.storage)?;
     // ensure we are migrating from an allowed contract
     if ver.contract != CONTRACT_NAME {
@@ -56,6 +75,10 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, any
         return Err(StdError::generic_err("Cannot upgrade from a newer version").into());
     }
 
+    // no state change introduced so far requires gating on the exact
+    // version we're upgrading from; add a check against
+    // `migrate_info.previous_version` here if a future migration does
+
     // set the new version
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
@@ -121,16 +144,130 @@ fn post_message_ibc(
         res_with_tx_index.add_attribute("message.block_height", block_height);
 
     // Send the result attributes over IBC on this channel
-    let packet = WormholeIbcPacketMsg::Publish {
-        msg: res_with_block_height,
-    };
-    IbcMsg::SendPacket {
+    let id = next_packet_sequence(deps.storage, &channel_id)?;
+    let send_packet = new_send_packet(deps.storage, channel_id, id, res_with_block_height.clone(), packet_timeout)?;
+
+    Ok(res_with_block_height.add_message(send_packet))
+}
+
+/// Assign and persist the next per-channel outgoing packet sequence number.
+fn next_packet_sequence(storage: &mut dyn cosmwasm_std::Storage, channel_id: &str) -> StdResult<u64> {
+    let id = NEXT_PACKET_SEQUENCE
+        .may_load(storage, channel_id.to_string())?
+        .unwrap_or_default();
+    NEXT_PACKET_SEQUENCE.save(storage, channel_id.to_string(), &(id + 1))?;
+    Ok(id)
+}
+
+/// Record an outgoing packet as pending and build the `IbcMsg::SendPacket` for it.
+fn new_send_packet(
+    storage: &mut dyn cosmwasm_std::Storage,
+    channel_id: String,
+    id: u64,
+    msg: Response,
+    timeout: cosmwasm_std::IbcTimeout,
+) -> anyhow::Result<IbcMsg> {
+    OUTGOING_PACKETS.save(
+        storage,
+        (channel_id.clone(), id),
+        &OutgoingPacket {
+            status: PacketStatus::Pending,
+        },
+    )?;
+
+    let packet = WormholeIbcPacketMsg::Publish { id, msg };
+    Ok(IbcMsg::SendPacket {
         channel_id,
         data: to_binary(&packet)?,
-        timeout: packet_timeout,
-    };
+        timeout,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketAckMsg,
+) -> anyhow::Result<IbcBasicResponse> {
+    let channel_id = msg.original_packet.src.channel_id.clone();
+    let sent: WormholeIbcPacketMsg = cosmwasm_std::from_binary(&msg.original_packet.data)
+        .context("failed to parse original packet data")?;
+    let WormholeIbcPacketMsg::Publish { id, msg: published } = sent;
+
+    // the receiving contract acks "ok" on success and "error: ..." when it
+    // rejected the packet at the application level (e.g. a connection_id
+    // mismatch); a packet can be relayed successfully yet still be rejected,
+    // so the ack payload -- not just its mere presence -- decides delivery.
+    let ack: String = cosmwasm_std::from_binary(&msg.acknowledgement.data)
+        .context("failed to parse packet acknowledgement")?;
+    let delivered = !ack.starts_with("error: ");
+
+    OUTGOING_PACKETS.update(deps.storage, (channel_id.clone(), id), |packet| {
+        packet
+            .map(|mut p| {
+                p.status = if delivered {
+                    PacketStatus::Delivered
+                } else {
+                    PacketStatus::Failed
+                };
+                p
+            })
+            .context("unknown outgoing packet acknowledged")
+    })?;
 
-    Ok(Response::default())
+    let mut response = IbcBasicResponse::new()
+        .add_attribute("action", "ibc_packet_ack")
+        .add_attribute("channel_id", channel_id.clone())
+        .add_attribute("id", id.to_string())
+        .add_attribute("ack", ack);
+
+    if !delivered {
+        // the receiving side rejected the packet at the application level,
+        // so re-enqueue it the same way a timed-out packet is re-enqueued
+        let new_id = next_packet_sequence(deps.storage, &channel_id)?;
+        let new_timeout = env.block.time.plus_seconds(PACKET_LIFETIME).into();
+        let resend = new_send_packet(deps.storage, channel_id, new_id, published, new_timeout)?;
+        response = response
+            .add_message(resend)
+            .add_attribute("requeued_id", new_id.to_string());
+    }
+
+    Ok(response)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> anyhow::Result<IbcBasicResponse> {
+    let channel_id = msg.packet.src.channel_id.clone();
+    let sent: WormholeIbcPacketMsg = cosmwasm_std::from_binary(&msg.packet.data)
+        .context("failed to parse timed out packet data")?;
+    let WormholeIbcPacketMsg::Publish { id, msg: published } = sent;
+
+    OUTGOING_PACKETS.update(deps.storage, (channel_id.clone(), id), |packet| {
+        packet
+            .map(|mut p| {
+                p.status = PacketStatus::Failed;
+                p
+            })
+            .context("unknown outgoing packet timed out")
+    })?;
+
+    // the core message was still posted successfully; only the IBC relay
+    // missed its window, so re-enqueue the same publish with a fresh timeout
+    // instead of dropping it.
+    let new_id = next_packet_sequence(deps.storage, &channel_id)?;
+    let new_timeout = env.block.time.plus_seconds(PACKET_LIFETIME).into();
+    let resend = new_send_packet(deps.storage, channel_id.clone(), new_id, published, new_timeout)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_message(resend)
+        .add_attribute("action", "ibc_packet_timeout")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("id", id.to_string())
+        .add_attribute("requeued_id", new_id.to_string()))
 }
 
 /// Find any IBC channel that is connected to the wormchain integrator contract
@@ -166,6 +303,14 @@ fn handle_submit_wormchain_receiver_update_vaa(
         bail!(ContractError::InvalidVAAType);
     }
 
+    // reject replays of a governance VAA we have already acted on, so the
+    // same registration or override cannot be submitted more than once
+    let digest = Binary::from(vaa.hash.clone()).to_string();
+    if PROCESSED_GOVERNANCE_VAAS.has(deps.storage, digest.clone()) {
+        bail!(ContractError::VAAAlreadyProcessed);
+    }
+    PROCESSED_GOVERNANCE_VAAS.save(deps.storage, digest, &true)?;
+
     // parse out the governance message from the VAA payload
     let govpacket: GovernancePacket = serde_wormhole::from_slice(&vaa.payload)
         .context("failed to parse governance packet")?;
@@ -175,7 +320,11 @@ fn handle_submit_wormchain_receiver_update_vaa(
         "this governance VAA is for another chain"
     );
 
-    // ensure that this action is for registering an emitter from wormchain
+    // ensure that this action is for registering (or overriding a previous
+    // registration of) an emitter from wormchain. A later governance VAA
+    // registering the same chain again overrides the stored address, so a
+    // compromised or rotated wormchain-ibc-receiver can be replaced rather
+    // than being permanently pinned by the first registration.
     match govpacket.action {
         Action::RegisterChain {
             chain,
@@ -187,16 +336,18 @@ fn handle_submit_wormchain_receiver_update_vaa(
             let wormchain_ibc_receiver_addr = String::from_utf8(emitter_address.0.to_vec())
                 .context("failed to parse chain registration address")?;
 
+            let is_update = WORMCHAIN_IBC_RECEIVER_ADDR.may_load(deps.storage)?.is_some();
             WORMCHAIN_IBC_RECEIVER_ADDR
                 .save(
                     deps.storage,
                     &wormchain_ibc_receiver_addr,
                 )
                 .context("failed to save chain registration")?;
-            let event = Event::new("RegisterChain")
+            let event_name = if is_update { "UpdateChain" } else { "RegisterChain" };
+            let event = Event::new(event_name)
                 .add_attribute("chain", chain.to_string())
                 .add_attribute("emitter_address", emitter_address.to_string());
-            
+
             Ok(Response::new()
                 .add_attribute("action", "submit_wormchain_receiver_update_vaa")
                 .add_attribute("owner", info.sender)
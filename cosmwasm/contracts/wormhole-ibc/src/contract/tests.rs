@@ -3,16 +3,9 @@
 // 2. failure - mock wormhole core bridge function to fail
 
 // handle_submit_wormchain_receiver_update_vaa
-// 1. failure - parsing vaa fails (mock core contract function to fail)
-// 2. failure - invalid chain
-// 3. failure - invalid emitter address
-// 4. failure - parsing governance packet
-// 5. failure - invalid governance chain (can we generate tests for all chains aside from the chain::any?)
-// 6. failure - not a Action::RegisterChain governance action
-// 7. failure - chain we are registering is not wormchain
-// 8. failure - parsing wormchain_ibc_receiver_addr fails
-// 9. failure - saving wormchain_ibc_receiver_addr in storage? Need to mock to make this work
-// 10. success - validate the correct response with the event and attributes is returned
+// 5. failure - not a Action::RegisterChain governance action
+// 6. failure - chain we are registering is not wormchain
+// 7. failure - saving wormchain_ibc_receiver_addr in storage? Need to mock to make this work
 
 // post_message_ibc
 // 1. failure - mock the querier to fail
@@ -23,4 +16,314 @@
 
 // find_wormchain_channel_id
 // 1. failure - no matching channel found
-// 2. success - matching channel found (happy path)
\ No newline at end of file
+// 2. success - matching channel found (happy path)
+
+mod migrate {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{to_binary, Addr, ContractInfoResponse, ContractResult, MigrateInfo,
+        SystemResult, WasmQuery};
+    use cw2::set_contract_version;
+    use wormhole::msg::MigrateMsg;
+
+    use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
+
+    const ADMIN: &str = "admin";
+
+    #[test]
+    fn migrate_rejects_non_admin_sender() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::ContractInfo { .. } => {
+                let mut info = ContractInfoResponse::new(1, "creator");
+                info.admin = Some(ADMIN.to_string());
+                SystemResult::Ok(ContractResult::Ok(to_binary(&info).unwrap()))
+            }
+            _ => panic!("unexpected query in migrate admin test"),
+        });
+
+        let migrate_info = MigrateInfo {
+            sender: Addr::unchecked("not-the-admin"),
+            previous_version: Some(CONTRACT_VERSION.to_string()),
+        };
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}, migrate_info).unwrap_err();
+        assert!(err.to_string().contains("configured admin"));
+    }
+}
+
+#[cfg(feature = "test-utils")]
+mod governance {
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{Binary, Coin, OwnedDeps};
+    use wormhole::msg::InstantiateMsg as CoreInstantiateMsg;
+    use wormhole::state::{GuardianAddress, GuardianSetInfo};
+    use wormhole_sdk::token::{Action, GovernancePacket};
+    use wormhole_sdk::Chain;
+
+    use crate::contract::{execute, handle_submit_wormchain_receiver_update_vaa, instantiate};
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::state::WORMCHAIN_IBC_RECEIVER_ADDR;
+    use crate::test_utils::MockGuardianSet;
+
+    const SENDER: &str = "creator";
+
+    fn setup() -> (OwnedDeps<MockStorage, MockApi, MockQuerier>, MockGuardianSet) {
+        let guardians = MockGuardianSet::new();
+        let mut deps = mock_dependencies();
+
+        let msg = CoreInstantiateMsg {
+            gov_chain: Chain::Solana.into(),
+            gov_address: Binary::from(wormhole_sdk::GOVERNANCE_EMITTER.0.to_vec()),
+            guardian_set_expirity: 86400,
+            initial_guardian_set: GuardianSetInfo {
+                addresses: guardians
+                    .addresses()
+                    .into_iter()
+                    .map(|bytes| GuardianAddress {
+                        bytes: Binary::from(bytes.to_vec()),
+                    })
+                    .collect(),
+                expiration_time: 0,
+            },
+            chain_id: Chain::Wormchain.into(),
+            fee: Coin::new(0, "uworm"),
+        };
+
+        instantiate(deps.as_mut(), mock_env(), mock_info(SENDER, &[]), msg).unwrap();
+        (deps, guardians)
+    }
+
+    #[test]
+    fn register_chain_happy_path() {
+        let (mut deps, guardians) = setup();
+        let vaa = guardians.register_chain_vaa(Chain::Wormchain, wormhole_sdk::Address([1u8; 32]));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateWormchainReceiverVAA { vaa },
+        )
+        .unwrap();
+
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(res.events[0].ty, "RegisterChain");
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "action" && a.value == "submit_wormchain_receiver_update_vaa"));
+        assert!(res.attributes.iter().any(|a| a.key == "owner" && a.value == SENDER));
+
+        let stored = WORMCHAIN_IBC_RECEIVER_ADDR.load(deps.as_ref().storage).unwrap();
+        assert_eq!(stored, String::from_utf8([1u8; 32].to_vec()).unwrap());
+    }
+
+    #[test]
+    fn register_chain_overrides_on_later_vaa() {
+        let (mut deps, guardians) = setup();
+        let first = guardians.register_chain_vaa(Chain::Wormchain, wormhole_sdk::Address([1u8; 32]));
+        let second = guardians.register_chain_vaa(Chain::Wormchain, wormhole_sdk::Address([2u8; 32]));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateWormchainReceiverVAA { vaa: first },
+        )
+        .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateWormchainReceiverVAA { vaa: second },
+        )
+        .unwrap();
+
+        assert_eq!(res.events[0].ty, "UpdateChain");
+        let stored = WORMCHAIN_IBC_RECEIVER_ADDR.load(deps.as_ref().storage).unwrap();
+        assert_eq!(stored, String::from_utf8([2u8; 32].to_vec()).unwrap());
+    }
+
+    #[test]
+    fn register_chain_rejects_replayed_vaa() {
+        let (mut deps, guardians) = setup();
+        let vaa = guardians.register_chain_vaa(Chain::Wormchain, wormhole_sdk::Address([1u8; 32]));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateWormchainReceiverVAA { vaa: vaa.clone() },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::SubmitUpdateWormchainReceiverVAA { vaa },
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ContractError>(),
+            Some(ContractError::VAAAlreadyProcessed)
+        ));
+    }
+
+    #[test]
+    fn register_chain_rejects_non_wormchain_target() {
+        let (mut deps, guardians) = setup();
+        let vaa = guardians.register_chain_vaa(Chain::Ethereum, wormhole_sdk::Address([1u8; 32]));
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ContractError>(),
+            Some(ContractError::InvalidChainRegistration)
+        ));
+    }
+
+    #[test]
+    fn register_chain_rejects_non_governance_emitter() {
+        let (mut deps, guardians) = setup();
+        let vaa = guardians.sign_vaa(Chain::Ethereum, wormhole_sdk::Address([9u8; 32]), 0, vec![]);
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ContractError>(),
+            Some(ContractError::InvalidVAAType)
+        ));
+    }
+
+    #[test]
+    fn register_chain_rejects_wrong_emitter_chain_only() {
+        // emitter_chain is wrong, but emitter_address does match GOVERNANCE_EMITTER
+        let (mut deps, guardians) = setup();
+        let vaa = guardians.sign_vaa(Chain::Ethereum, wormhole_sdk::GOVERNANCE_EMITTER, 0, vec![]);
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ContractError>(),
+            Some(ContractError::InvalidVAAType)
+        ));
+    }
+
+    #[test]
+    fn register_chain_rejects_wrong_emitter_address_only() {
+        // emitter_chain is Solana, but emitter_address does not match GOVERNANCE_EMITTER
+        let (mut deps, guardians) = setup();
+        let vaa = guardians.sign_vaa(Chain::Solana, wormhole_sdk::Address([9u8; 32]), 0, vec![]);
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ContractError>(),
+            Some(ContractError::InvalidVAAType)
+        ));
+    }
+
+    #[test]
+    fn register_vaa_rejects_malformed_vaa() {
+        let (mut deps, _guardians) = setup();
+        // not a well-formed wormhole VAA, so verification/parsing itself fails
+        let vaa = Binary::from(vec![0xffu8; 8]);
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(err.downcast_ref::<ContractError>().is_none());
+    }
+
+    #[test]
+    fn register_chain_rejects_non_any_governance_chain() {
+        let (mut deps, guardians) = setup();
+        let govpacket = GovernancePacket {
+            chain: Chain::Wormchain,
+            action: Action::RegisterChain {
+                chain: Chain::Wormchain,
+                emitter_address: wormhole_sdk::Address([1u8; 32]),
+            },
+        };
+        let payload = serde_wormhole::to_vec(&govpacket).unwrap();
+        let vaa = guardians.sign_vaa(Chain::Solana, wormhole_sdk::GOVERNANCE_EMITTER, 0, payload);
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("another chain"));
+    }
+
+    #[test]
+    fn register_chain_rejects_malformed_governance_packet() {
+        let (mut deps, guardians) = setup();
+        let vaa =
+            guardians.sign_vaa(Chain::Solana, wormhole_sdk::GOVERNANCE_EMITTER, 0, vec![0xffu8; 4]);
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("governance packet"));
+    }
+
+    #[test]
+    fn register_chain_rejects_non_utf8_emitter_address() {
+        let (mut deps, guardians) = setup();
+        // 0x80 alone is an invalid UTF-8 continuation byte
+        let vaa = guardians.register_chain_vaa(Chain::Wormchain, wormhole_sdk::Address([0x80u8; 32]));
+
+        let err = handle_submit_wormchain_receiver_update_vaa(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            vaa,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("chain registration address"));
+    }
+}
@@ -5,7 +5,9 @@ pub enum ContractError {
     #[error("non governance vaa")]
     InvalidVAAType,
     #[error("non wormchain emitter registration")]
-    InvalidChainRegistration
+    InvalidChainRegistration,
+    #[error("vaa has already been processed")]
+    VAAAlreadyProcessed,
 }
 
 // Workaround for not being able to use the `bail!` macro directly.
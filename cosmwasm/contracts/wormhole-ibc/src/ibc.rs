@@ -0,0 +1,3 @@
+/// How long a published message's IBC packet is allowed to stay in flight
+/// before the counterparty chain times it out, in seconds.
+pub const PACKET_LIFETIME: u64 = 60 * 10;
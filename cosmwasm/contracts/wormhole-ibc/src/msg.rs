@@ -17,5 +17,12 @@ pub enum ExecuteMsg {
 /// This is the message we send over the IBC channel
 #[cw_serde]
 pub enum WormholeIbcPacketMsg {
-    Publish { msg: Response }
+    Publish {
+        /// Our own per-channel sequence number, assigned before the chain's
+        /// IBC packet sequence is known, so `ibc_packet_ack`/`ibc_packet_timeout`
+        /// can correlate the delivery outcome back to the outgoing packet we
+        /// recorded in state.
+        id: u64,
+        msg: Response,
+    },
 }
\ No newline at end of file
@@ -0,0 +1,32 @@
+use cosmwasm_schema::cw_serde;
+use cw_storage_plus::{Item, Map};
+
+/// The address of the wormchain-ibc-receiver contract registered via
+/// governance, used as the IBC publish target for outgoing core messages.
+pub const WORMCHAIN_IBC_RECEIVER_ADDR: Item<String> = Item::new("wormchain_ibc_receiver_addr");
+
+/// The delivery outcome of an outgoing IBC packet.
+#[cw_serde]
+pub enum PacketStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+/// An outgoing packet sent over IBC to the wormchain integrator contract.
+#[cw_serde]
+pub struct OutgoingPacket {
+    pub status: PacketStatus,
+}
+
+/// Outgoing packets, keyed by the channel they were sent on and our own
+/// per-channel sequence number (see `WormholeIbcPacketMsg::Publish::id`).
+pub const OUTGOING_PACKETS: Map<(String, u64), OutgoingPacket> = Map::new("outgoing_packets");
+
+/// The next per-channel sequence number to assign to an outgoing packet.
+pub const NEXT_PACKET_SEQUENCE: Map<String, u64> = Map::new("next_packet_sequence");
+
+/// The double-keccak256 digest of every governance VAA that has already been
+/// processed by `handle_submit_wormchain_receiver_update_vaa`, so the same
+/// VAA cannot be replayed.
+pub const PROCESSED_GOVERNANCE_VAAS: Map<String, bool> = Map::new("processed_governance_vaas");
@@ -0,0 +1,133 @@
+//! Helpers for building and signing VAAs under a deterministic mock guardian
+//! set, gated behind the `test-utils` feature so they never ship in a
+//! production build. Mirrors the `pythnet_sdk` test-utils module: fixed,
+//! well-known keys only, never anything derived at runtime.
+
+use cosmwasm_std::Binary;
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+use sha3::{Digest, Keccak256};
+use wormhole_sdk::token::{Action, GovernancePacket};
+use wormhole_sdk::{Address, Chain, GOVERNANCE_EMITTER};
+
+/// Fixed, publicly-known secp256k1 keys. These back the mock guardian set and
+/// must never be treated as anything other than test fixtures.
+const GUARDIAN_KEYS: &[[u8; 32]] = &[
+    [1u8; 32],
+    [2u8; 32],
+    [3u8; 32],
+    [4u8; 32],
+    [5u8; 32],
+    [6u8; 32],
+    [7u8; 32],
+];
+
+/// A deterministic guardian set used to sign VAAs in tests.
+pub struct MockGuardianSet {
+    index: u32,
+    keys: Vec<SecretKey>,
+}
+
+impl MockGuardianSet {
+    /// Build the mock guardian set at guardian set index 0 using all of the
+    /// fixed [`GUARDIAN_KEYS`].
+    pub fn new() -> Self {
+        Self::with_index(0)
+    }
+
+    /// Build the mock guardian set at a specific guardian set index, so tests
+    /// can exercise guardian set rotation.
+    pub fn with_index(index: u32) -> Self {
+        let keys = GUARDIAN_KEYS
+            .iter()
+            .map(|k| SecretKey::from_slice(k).expect("valid fixed guardian key"))
+            .collect();
+        Self { index, keys }
+    }
+
+    /// The guardian set index this mock guardian set signs under.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The uncompressed eth-style addresses (last 20 bytes of keccak256 of
+    /// the uncompressed pubkey) of the guardians in this set, in the order
+    /// they sign in.
+    pub fn addresses(&self) -> Vec<[u8; 20]> {
+        let secp = Secp256k1::signing_only();
+        self.keys
+            .iter()
+            .map(|k| {
+                let pubkey = PublicKey::from_secret_key(&secp, k);
+                let uncompressed = pubkey.serialize_uncompressed();
+                // skip the leading 0x04 prefix byte
+                let hash = Keccak256::digest(&uncompressed[1..]);
+                let mut addr = [0u8; 20];
+                addr.copy_from_slice(&hash[12..]);
+                addr
+            })
+            .collect()
+    }
+
+    /// Build and sign a VAA carrying `payload`, signed by every guardian in
+    /// the mock set, in the standard wormhole wire format:
+    /// `version(1) || guardian_set_index(u32) || num_sigs(u8) || signatures`
+    /// where each signature is `guardian_index(u8) || recoverable_sig(65)`.
+    pub fn sign_vaa(
+        &self,
+        emitter_chain: Chain,
+        emitter_address: Address,
+        sequence: u64,
+        payload: Vec<u8>,
+    ) -> Binary {
+        let mut body = Vec::new();
+        body.extend_from_slice(&0u32.to_be_bytes()); // timestamp
+        body.extend_from_slice(&0u32.to_be_bytes()); // nonce
+        body.extend_from_slice(&u16::from(emitter_chain).to_be_bytes());
+        body.extend_from_slice(&emitter_address.0);
+        body.extend_from_slice(&sequence.to_be_bytes());
+        body.push(0u8); // consistency_level
+        body.extend_from_slice(&payload);
+
+        let digest = Keccak256::digest(Keccak256::digest(&body));
+        let message = Message::from_slice(&digest).expect("32-byte digest");
+
+        let secp = Secp256k1::signing_only();
+        let mut vaa = Vec::new();
+        vaa.push(1u8); // version
+        vaa.extend_from_slice(&self.index.to_be_bytes());
+        vaa.push(self.keys.len() as u8);
+
+        for (guardian_index, key) in self.keys.iter().enumerate() {
+            let (recovery_id, sig) = secp.sign_ecdsa_recoverable(&message, key).serialize_compact();
+            vaa.push(guardian_index as u8);
+            vaa.extend_from_slice(&sig);
+            vaa.push(recovery_id.to_i32() as u8);
+        }
+
+        vaa.extend_from_slice(&body);
+        Binary::from(vaa)
+    }
+
+    /// Build a governance VAA registering `emitter_address` as the
+    /// Wormchain emitter for `chain`, signed by this mock guardian set, so
+    /// the governance happy-path and failure cases can be exercised without
+    /// a live guardian network.
+    pub fn register_chain_vaa(&self, chain: Chain, emitter_address: Address) -> Binary {
+        let govpacket = GovernancePacket {
+            chain: Chain::Any,
+            action: Action::RegisterChain {
+                chain,
+                emitter_address,
+            },
+        };
+        let payload = serde_wormhole::to_vec(&govpacket).expect("governance packet serializes");
+
+        self.sign_vaa(Chain::Solana, GOVERNANCE_EMITTER, 0, payload)
+    }
+}
+
+impl Default for MockGuardianSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}